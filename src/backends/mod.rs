@@ -0,0 +1,24 @@
+//! Transports that deliver a `WireMessage` to a GELF server.
+
+mod udp;
+mod tcp;
+
+pub use self::udp::UdpBackend;
+pub use self::tcp::TcpBackend;
+
+use errors::Result;
+use message::WireMessage;
+
+/// A transport capable of delivering a `WireMessage` to a GELF server.
+///
+/// Implementations decide how (and whether) to compress, frame and retry a
+/// message; `Logger` only cares about the resulting `Result`.
+///
+/// `Send + Sync` is required because `Logger` boxes its backend and itself
+/// implements `log::Log`, which must be `Send + Sync` to be installed via
+/// `log::set_logger`.
+pub trait Backend: Send + Sync {
+    /// Deliver `message` to the configured server, returning an error if
+    /// delivery failed.
+    fn log(&self, message: WireMessage) -> Result<()>;
+}
@@ -0,0 +1,159 @@
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::Mutex;
+
+#[cfg(feature = "tls")]
+use native_tls::{TlsConnector, TlsStream};
+
+use backends::Backend;
+use message::WireMessage;
+use errors::{Result, ErrorKind, ResultExt};
+
+/// The byte GELF-over-TCP uses to delimit messages on the wire.
+const MESSAGE_DELIMITER: u8 = 0;
+
+enum Stream {
+    Plain(TcpStream),
+    #[cfg(feature = "tls")]
+    Tls(TlsStream<TcpStream>),
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+        match *self {
+            Stream::Plain(ref mut stream) => stream.write(buf),
+            #[cfg(feature = "tls")]
+            Stream::Tls(ref mut stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> ::std::io::Result<()> {
+        match *self {
+            Stream::Plain(ref mut stream) => stream.flush(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(ref mut stream) => stream.flush(),
+        }
+    }
+}
+
+/// A `Backend` sending GELF messages over a persistent, null-byte-delimited
+/// TCP stream, with optional TLS.
+///
+/// GELF over TCP requires each message to be terminated with a `\0` byte and
+/// forbids compression, since Graylog's TCP input reads a stream of
+/// null-delimited, uncompressed JSON documents rather than discrete datagrams.
+/// `TcpBackend` therefore always serializes `WireMessage`s as plain JSON; it
+/// never applies `MessageCompression`, regardless of what a caller might have
+/// configured elsewhere.
+///
+/// The underlying connection is established eagerly in `new`/`new_with_tls`
+/// (construction fails if the server cannot be reached) and then kept open
+/// across calls to `log`. If a write fails, the stream is dropped, a fresh
+/// connection is established and the same message is written once more; if
+/// that second attempt fails too, `log` returns the error.
+///
+/// ``` no_run
+/// use gelf::{Logger, TcpBackend, Message, Level};
+///
+/// let backend = TcpBackend::new("127.0.0.1:12201").expect("Failed to connect");
+/// let logger = Logger::new(Box::new(backend)).expect("Failed to determine hostname");
+///
+/// logger.log_message(Message::new("Test log message!", Some(Level::Debug)));
+/// ```
+pub struct TcpBackend {
+    addr: String,
+    use_tls: bool,
+    stream: Mutex<Option<Stream>>,
+}
+
+impl TcpBackend {
+    /// Construct a new, unencrypted `TcpBackend` connecting to `addr`.
+    pub fn new<A: Into<String>>(addr: A) -> Result<TcpBackend> {
+        let backend = TcpBackend {
+            addr: addr.into(),
+            use_tls: false,
+            stream: Mutex::new(None),
+        };
+
+        backend.connect().map(|_| backend)
+    }
+
+    /// Construct a new `TcpBackend` connecting to `addr` over TLS.
+    ///
+    /// Requires the `tls` feature.
+    #[cfg(feature = "tls")]
+    pub fn new_with_tls<A: Into<String>>(addr: A) -> Result<TcpBackend> {
+        let backend = TcpBackend {
+            addr: addr.into(),
+            use_tls: true,
+            stream: Mutex::new(None),
+        };
+
+        backend.connect().map(|_| backend)
+    }
+
+    fn connect(&self) -> Result<()> {
+        let tcp_stream = TcpStream::connect(&self.addr as &str)
+            .chain_err(|| ErrorKind::LoggerCreateFailed("Failed to connect TcpBackend"))?;
+
+        let stream = if self.use_tls {
+            self.wrap_tls(tcp_stream)?
+        } else {
+            Stream::Plain(tcp_stream)
+        };
+
+        *self.stream.lock().unwrap() = Some(stream);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    fn wrap_tls(&self, tcp_stream: TcpStream) -> Result<Stream> {
+        let connector = TlsConnector::builder()
+            .and_then(|builder| builder.build())
+            .chain_err(|| ErrorKind::LoggerCreateFailed("Failed to build TLS connector"))?;
+
+        let domain = self.addr.split(':').next().unwrap_or(&self.addr);
+
+        connector.connect(domain, tcp_stream)
+            .map(Stream::Tls)
+            .chain_err(|| ErrorKind::LoggerCreateFailed("Failed to establish TLS session"))
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn wrap_tls(&self, _tcp_stream: TcpStream) -> Result<Stream> {
+        Err(ErrorKind::LoggerCreateFailed("TLS support requires the \"tls\" feature").into())
+    }
+
+    fn write_framed(&self, payload: &[u8]) -> Result<()> {
+        let mut guard = self.stream.lock().unwrap();
+
+        let write_result = {
+            let stream = guard.as_mut().ok_or(ErrorKind::SendMessageFailed("tcp"))?;
+            stream.write_all(payload)
+                .and_then(|_| stream.write_all(&[MESSAGE_DELIMITER]))
+        };
+
+        if write_result.is_err() {
+            *guard = None;
+        }
+
+        write_result.chain_err(|| ErrorKind::SendMessageFailed("tcp"))
+    }
+}
+
+impl Backend for TcpBackend {
+    /// Send a `WireMessage` as uncompressed, null-byte-terminated JSON. If the
+    /// write fails (including when an earlier failure left no open stream),
+    /// reconnect and write the message once more.
+    fn log(&self, message: WireMessage) -> Result<()> {
+        let json = message.to_gelf()?;
+
+        if self.write_framed(json.as_bytes()).is_err() {
+            self.connect()?;
+            self.write_framed(json.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
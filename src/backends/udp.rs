@@ -0,0 +1,72 @@
+use std::net::UdpSocket;
+
+use backends::Backend;
+use message::{MessageCompression, WireMessage};
+use message::compression::DEFAULT_COMPRESSION_THRESHOLD;
+use errors::{Result, ErrorKind, ResultExt};
+
+/// A `Backend` sending compressed GELF messages as UDP datagrams.
+///
+/// This is the simplest and most common GELF transport: each `WireMessage` is
+/// compressed with the configured `MessageCompression` and sent as a single
+/// datagram. Delivery is fire-and-forget, matching UDP's own guarantees.
+///
+/// ``` no_run
+/// use gelf::{Logger, UdpBackend, Message, Level};
+///
+/// let backend = UdpBackend::new("127.0.0.1:12201").expect("Failed to create UDP backend");
+/// let logger = Logger::new(Box::new(backend)).expect("Failed to determine hostname");
+///
+/// logger.log_message(Message::new("Test log message!", Some(Level::Debug)));
+/// ```
+pub struct UdpBackend {
+    socket: UdpSocket,
+    addr: String,
+    compression: MessageCompression,
+    threshold: usize,
+}
+
+impl UdpBackend {
+    /// Construct a new `UdpBackend` sending to `addr`, using the default
+    /// `MessageCompression` and `DEFAULT_COMPRESSION_THRESHOLD`.
+    pub fn new<A: Into<String>>(addr: A) -> Result<UdpBackend> {
+        UdpBackend::with_compression(addr, MessageCompression::default())
+    }
+
+    /// Construct a new `UdpBackend` sending to `addr`, compressing each
+    /// message with `compression` once it reaches `DEFAULT_COMPRESSION_THRESHOLD`
+    /// bytes of JSON.
+    pub fn with_compression<A: Into<String>>(addr: A, compression: MessageCompression) -> Result<UdpBackend> {
+        UdpBackend::with_threshold(addr, compression, DEFAULT_COMPRESSION_THRESHOLD)
+    }
+
+    /// Construct a new `UdpBackend` sending to `addr`, compressing each
+    /// message with `compression`, but only once its serialized JSON reaches
+    /// `threshold` bytes; shorter messages are sent uncompressed, since
+    /// compression overhead can outweigh the ratio gained on small payloads.
+    pub fn with_threshold<A: Into<String>>(addr: A,
+                                            compression: MessageCompression,
+                                            threshold: usize)
+                                            -> Result<UdpBackend> {
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .chain_err(|| ErrorKind::LoggerCreateFailed("Failed to bind UDP socket"))?;
+
+        Ok(UdpBackend {
+            socket: socket,
+            addr: addr.into(),
+            compression: compression,
+            threshold: threshold,
+        })
+    }
+}
+
+impl Backend for UdpBackend {
+    fn log(&self, message: WireMessage) -> Result<()> {
+        let payload = self.compression.compress_with_threshold(&message, self.threshold)?;
+
+        self.socket.send_to(&payload, &self.addr as &str)
+            .chain_err(|| ErrorKind::SendMessageFailed("udp"))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use std::sync::mpsc::{self, Sender, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use backends::Backend;
+use message::WireMessage;
+use errors::Result;
+use logger::ErrorHandler;
+
+/// Default number of messages buffered before a flush is triggered.
+pub const DEFAULT_MAX_BUFFER_LENGTH: usize = 500;
+
+/// Default maximum age of the oldest buffered message before a flush is triggered.
+pub fn default_max_buffer_age() -> Duration {
+    Duration::from_millis(300)
+}
+
+enum Command {
+    Log(WireMessage),
+    Flush(Sender<()>),
+}
+
+/// A `Backend` wrapper that batches `WireMessage`s instead of sending one per
+/// `log_message` call.
+///
+/// Messages are handed off to a dedicated background thread over a channel, so
+/// the call site never blocks on the backend's network I/O. The background
+/// thread flushes the accumulated batch to the wrapped backend whenever either
+/// `max_length` messages have been buffered or `max_age` has elapsed since the
+/// last flush, whichever comes first. Dropping the `BufferedLogger` flushes any
+/// remaining messages before the background thread shuts down.
+///
+/// `BufferedLogger` itself implements `Backend`, so it can be boxed up and
+/// installed on a `Logger` just like any other backend, including via
+/// `Logger::install` (the channel hand-off is `Sync`, not just `Send`).
+///
+/// Delivery happens on the background flushing thread, not on the call site,
+/// so `Logger`'s own `panic_on_error`/error-handler machinery never sees these
+/// failures — as far as `Logger::log_message` is concerned, handing a message
+/// to a `BufferedLogger` always succeeds. Register a handler with
+/// `set_error_handler` to observe delivery failures from the wrapped backend.
+///
+/// ``` no_run
+/// use gelf::{Logger, BufferedLogger, UdpBackend};
+///
+/// let backend = UdpBackend::new("127.0.0.1:12201").expect("Failed to create UDP backend");
+/// let buffered = BufferedLogger::with_defaults(Box::new(backend));
+/// buffered.set_error_handler(Box::new(|error| eprintln!("delivery failed: {}", error)));
+/// let logger = Logger::new(Box::new(buffered)).expect("Failed to determine hostname");
+/// ```
+pub struct BufferedLogger {
+    // `mpsc::Sender` is `Send` but not `Sync`; the `Mutex` is what makes
+    // `BufferedLogger` itself `Sync`, which `Backend` (and thus
+    // `Logger::install`) requires.
+    sender: Mutex<Option<Sender<Command>>>,
+    worker: Option<JoinHandle<()>>,
+    error_handler: Arc<Mutex<Option<ErrorHandler>>>,
+}
+
+impl BufferedLogger {
+    /// Construct a `BufferedLogger` wrapping `backend`, flushing whenever
+    /// `max_length` messages have accumulated or `max_age` has passed since the
+    /// last flush.
+    pub fn new(backend: Box<Backend>, max_length: usize, max_age: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let error_handler = Arc::new(Mutex::new(None));
+        let worker_error_handler = error_handler.clone();
+
+        let worker = thread::spawn(move || {
+            let mut buffer = Vec::with_capacity(max_length);
+            let mut last_flush = Instant::now();
+
+            loop {
+                let elapsed = last_flush.elapsed();
+                let timeout = max_age.checked_sub(elapsed).unwrap_or_else(|| Duration::from_millis(0));
+
+                match receiver.recv_timeout(timeout) {
+                    Ok(Command::Log(message)) => {
+                        buffer.push(message);
+
+                        if buffer.len() >= max_length || last_flush.elapsed() >= max_age {
+                            Self::flush_buffer(&*backend, &mut buffer, &worker_error_handler);
+                            last_flush = Instant::now();
+                        }
+                    }
+                    Ok(Command::Flush(ack)) => {
+                        Self::flush_buffer(&*backend, &mut buffer, &worker_error_handler);
+                        last_flush = Instant::now();
+                        let _ = ack.send(());
+                    }
+                    Err(RecvTimeoutError::Timeout) => {
+                        Self::flush_buffer(&*backend, &mut buffer, &worker_error_handler);
+                        last_flush = Instant::now();
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        Self::flush_buffer(&*backend, &mut buffer, &worker_error_handler);
+                        break;
+                    }
+                }
+            }
+        });
+
+        BufferedLogger {
+            sender: Mutex::new(Some(sender)),
+            worker: Some(worker),
+            error_handler: error_handler,
+        }
+    }
+
+    /// Construct a `BufferedLogger` using `DEFAULT_MAX_BUFFER_LENGTH` and
+    /// `default_max_buffer_age()` as the flush triggers.
+    pub fn with_defaults(backend: Box<Backend>) -> Self {
+        Self::new(backend, DEFAULT_MAX_BUFFER_LENGTH, default_max_buffer_age())
+    }
+
+    /// Register a callback invoked on the background flushing thread whenever
+    /// the wrapped backend fails to deliver a batched message.
+    ///
+    /// Unlike `Logger::set_error_handler`, this takes `&self`: the handler is
+    /// shared with the already-running background thread via an `Arc<Mutex<_>>`
+    /// rather than being fixed at construction time.
+    pub fn set_error_handler(&self, handler: ErrorHandler) -> &Self {
+        *self.error_handler.lock().unwrap() = Some(handler);
+        self
+    }
+
+    /// Flush any buffered messages to the backend now, blocking until the
+    /// background thread has finished delivering them.
+    pub fn flush(&self) {
+        if let Some(ref sender) = *self.sender.lock().unwrap() {
+            let (ack_tx, ack_rx) = mpsc::channel();
+
+            if sender.send(Command::Flush(ack_tx)).is_ok() {
+                let _ = ack_rx.recv();
+            }
+        }
+    }
+
+    fn flush_buffer(backend: &Backend, buffer: &mut Vec<WireMessage>, error_handler: &Mutex<Option<ErrorHandler>>) {
+        for message in buffer.drain(..) {
+            if let Err(error) = backend.log(message) {
+                if let Some(ref handler) = *error_handler.lock().unwrap() {
+                    handler(&error);
+                }
+            }
+        }
+    }
+}
+
+impl Backend for BufferedLogger {
+    /// Buffer a message for later delivery.
+    ///
+    /// Never blocks on backend I/O; the message is merely handed off to the
+    /// background flushing thread, so this always returns `Ok` even if the
+    /// eventual delivery later fails. Implementing `Backend` lets a
+    /// `BufferedLogger` be installed wherever a backend is expected, e.g.
+    /// `Logger::new(Box::new(BufferedLogger::with_defaults(Box::new(inner))))`.
+    fn log(&self, message: WireMessage) -> Result<()> {
+        if let Some(ref sender) = *self.sender.lock().unwrap() {
+            let _ = sender.send(Command::Log(message));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for BufferedLogger {
+    fn drop(&mut self) {
+        self.flush();
+
+        // Dropping the sender disconnects the channel, which lets the
+        // background thread's `recv_timeout` observe `Disconnected` and exit
+        // after its final flush.
+        self.sender.lock().unwrap().take();
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! A [GELF](http://docs.graylog.org/en/2.2/pages/gelf.html) logger for Rust,
+//! compatible with the standard `log` crate and with backends for shipping
+//! messages to a server such as [Graylog](https://www.graylog.org/).
+
+extern crate log;
+extern crate hostname;
+extern crate libflate;
+#[macro_use]
+extern crate error_chain;
+#[cfg(feature = "tls")]
+extern crate native_tls;
+
+pub mod errors;
+pub mod message;
+pub mod backends;
+pub mod logger;
+pub mod buffered_logger;
+
+pub use errors::{Error, ErrorKind, Result};
+pub use message::{Level, Message, WireMessage, MessageCompression};
+pub use backends::{Backend, UdpBackend, TcpBackend};
+pub use logger::Logger;
+pub use buffered_logger::BufferedLogger;
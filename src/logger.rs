@@ -5,7 +5,12 @@ use hostname;
 
 use backends::Backend;
 use message::{Message, WireMessage};
-use errors::{Result, ErrorKind};
+use errors::{Error, Result, ErrorKind};
+
+/// A callback invoked whenever the backend fails to deliver a message.
+///
+/// Registered via `Logger::set_error_handler`.
+pub type ErrorHandler = Box<Fn(&Error) + Send + Sync>;
 
 /// Logger for sending log-messages in [GELF-format](http://docs.graylog.org/en/2.2/pages/gelf.html)
 /// to a GELF server, e.g. [Graylog](https://www.graylog.org/)
@@ -52,6 +57,8 @@ pub struct Logger {
     hostname: String,
     backend: Box<Backend>,
     default_metadata: HashMap<String, String>,
+    panic_on_error: bool,
+    error_handler: Option<ErrorHandler>,
 }
 
 impl Logger {
@@ -76,6 +83,8 @@ impl Logger {
             hostname: String::from(hostname),
             backend: backend,
             default_metadata: HashMap::new(),
+            panic_on_error: false,
+            error_handler: None,
         }
     }
 
@@ -100,8 +109,46 @@ impl Logger {
     ///
     /// The logger will automatically all `default_metadata` fields to the message
     /// which are missing in the passed `Message`.
+    ///
+    /// If the backend fails to deliver the message, the configured error policy
+    /// decides what happens: by default the error is silently dropped, but a
+    /// registered error handler is invoked first, and `panic_on_error` can be
+    /// enabled to turn delivery failures into panics.
     pub fn log_message(&self, msg: Message) {
-        self.backend.log(WireMessage::new(msg, &self));
+        if let Err(error) = self.backend.log(WireMessage::new(msg, &self)) {
+            if let Some(ref handler) = self.error_handler {
+                handler(&error);
+            }
+
+            if self.panic_on_error {
+                panic!("Failed to log message: {}", error);
+            }
+        }
+    }
+
+    /// Enable panicking whenever the backend fails to deliver a message.
+    ///
+    /// Disabled by default. Useful during development or for services that would
+    /// rather crash loudly than silently lose log messages.
+    pub fn enable_panic_on_error(&mut self) -> &mut Self {
+        self.panic_on_error = true;
+        self
+    }
+
+    /// Disable panicking on delivery failures (the default).
+    pub fn disable_panic_on_error(&mut self) -> &mut Self {
+        self.panic_on_error = false;
+        self
+    }
+
+    /// Register a callback that is invoked whenever the backend fails to
+    /// deliver a message.
+    ///
+    /// The handler runs before `panic_on_error` is evaluated, so it always sees
+    /// the error even if the logger is also configured to panic.
+    pub fn set_error_handler(&mut self, handler: ErrorHandler) -> &mut Self {
+        self.error_handler = Some(handler);
+        self
     }
 
     /// Return the hostname used for GELF's `host`-field
@@ -153,6 +200,14 @@ impl log::Log for Logger {
     }
 
     /// Logs the `LogRecord`.
+    ///
+    /// Besides the bare message, this also carries the record's source
+    /// location and target over into GELF's additional fields: `file` and
+    /// `line` are populated from `record.location()`, and the record's target
+    /// is added as `target`. `Message::set_metadata` prefixes these with `_`
+    /// on the wire, so Graylog ends up with `_file`/`_line`/`_target`, giving
+    /// jump-to-source context without any extra work at the call site.
+    ///
     /// See [docs](https://doc.rust-lang.org/log/log/trait.Log.html#tymethod.log)
     /// for more details
     fn log(&self, record: &log::LogRecord) {
@@ -160,6 +215,13 @@ impl log::Log for Logger {
             ()
         }
 
-        self.log_message(From::from(record))
+        let mut message: Message = From::from(record);
+
+        let location = record.location();
+        let _ = message.set_metadata(String::from("file"), String::from(location.file()));
+        let _ = message.set_metadata(String::from("line"), location.line().to_string());
+        let _ = message.set_metadata(String::from("target"), String::from(record.target()));
+
+        self.log_message(message)
     }
 }
\ No newline at end of file
@@ -2,41 +2,80 @@ use std::io;
 
 use libflate::gzip;
 use libflate::zlib;
+use libflate::lz77::{CompressionLevel, DefaultLz77Encoder};
 
 use errors::{Result, ErrorKind, ResultExt};
 use message::WireMessage;
 
+/// The default minimum serialized message size (in bytes) below which
+/// `compress_with_threshold` skips compression entirely.
+///
+/// Short messages compress poorly: the gzip/zlib framing overhead can make the
+/// compressed payload larger than the raw JSON, while still paying the CPU
+/// cost of running the encoder.
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 512;
+
 /// MessageCompression represents all possible compression algorithms in GELF.
 #[derive(PartialEq, Clone, Copy)]
 pub enum MessageCompression {
     None,
-    Gzip,
-    Zlib,
+    Gzip { level: CompressionLevel },
+    Zlib { level: CompressionLevel },
 }
 
 impl MessageCompression {
     /// Return the default compression algorithm.
     pub fn default() -> MessageCompression {
-        MessageCompression::Gzip
+        MessageCompression::Gzip { level: CompressionLevel::Balance }
+    }
+
+    /// Return this compression algorithm with a custom libflate LZ77
+    /// compression level, trading CPU for compression ratio. Has no effect on
+    /// `MessageCompression::None`.
+    pub fn with_level(self, level: CompressionLevel) -> MessageCompression {
+        match self {
+            MessageCompression::None => MessageCompression::None,
+            MessageCompression::Gzip { .. } => MessageCompression::Gzip { level: level },
+            MessageCompression::Zlib { .. } => MessageCompression::Zlib { level: level },
+        }
     }
 
     /// Compress a serialized message with the defined algorithm.
     pub fn compress(&self, message: &WireMessage) -> Result<Vec<u8>> {
+        self.compress_with_threshold(message, 0)
+    }
+
+    /// Compress a serialized message with the defined algorithm, unless its
+    /// JSON encoding is smaller than `threshold` bytes, in which case the raw
+    /// JSON is returned uncompressed regardless of the configured algorithm.
+    pub fn compress_with_threshold(&self, message: &WireMessage, threshold: usize) -> Result<Vec<u8>> {
         let json = message.to_gelf()?;
 
+        if json.len() < threshold {
+            return Ok(json.into_bytes());
+        }
+
         Ok(match *self {
             MessageCompression::None => json.into_bytes(),
-            MessageCompression::Gzip => {
+            MessageCompression::Gzip { level } => {
                 let mut cursor = io::Cursor::new(json);
-                gzip::Encoder::new(Vec::new()).and_then(|mut encoder| {
+                let lz77 = DefaultLz77Encoder::with_compression_level(level);
+                let options = gzip::EncodeOptions::with_lz77(lz77);
+
+                gzip::Encoder::with_options(Vec::new(), options)
+                    .and_then(|mut encoder| {
                         io::copy(&mut cursor, &mut encoder)
                             .and_then(|_| encoder.finish().into_result())
                     })
                     .chain_err(|| ErrorKind::CompressMessageFailed("gzip"))?
             }
-            MessageCompression::Zlib => {
+            MessageCompression::Zlib { level } => {
                 let mut cursor = io::Cursor::new(json);
-                zlib::Encoder::new(Vec::new()).and_then(|mut encoder| {
+                let lz77 = DefaultLz77Encoder::with_compression_level(level);
+                let options = zlib::EncodeOptions::with_lz77(lz77);
+
+                zlib::Encoder::with_options(Vec::new(), options)
+                    .and_then(|mut encoder| {
                         io::copy(&mut cursor, &mut encoder)
                             .and_then(|_| encoder.finish().into_result())
                     })